@@ -28,11 +28,234 @@ pub use crate::array::DecimalIter;
 use crate::buffer::Buffer;
 use crate::datatypes::DataType;
 use crate::datatypes::{
-    validate_decimal_precision, DECIMAL_DEFAULT_SCALE, DECIMAL_MAX_PRECISION,
-    DECIMAL_MAX_SCALE,
+    validate_decimal_precision, DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
+    DECIMAL_DEFAULT_SCALE, DECIMAL_MAX_PRECISION, DECIMAL_MAX_SCALE,
 };
 use crate::error::{ArrowError, Result};
 use crate::util::decimal::{BasicDecimal, Decimal128};
+use num_bigint::BigInt;
+
+/// Returns the number of base-10 digits needed to represent the signed,
+/// little-endian two's-complement integer in `value`.
+fn decimal_bytes_digit_count(value: &[u8]) -> usize {
+    let big_int = BigInt::from_signed_bytes_le(value);
+    big_int.to_string().trim_start_matches('-').len()
+}
+
+/// Validates that `value`, interpreted as a little-endian two's-complement
+/// integer, fits within `precision` decimal digits.
+fn validate_decimal_bytes_precision<const BYTE_WIDTH: usize>(
+    value: &[u8],
+    precision: usize,
+) -> Result<()> {
+    let digit_count = decimal_bytes_digit_count(value);
+    if digit_count > precision {
+        let big_int = BigInt::from_signed_bytes_le(value);
+        let type_name = match BYTE_WIDTH {
+            16 => "Decimal",
+            32 => "Decimal256",
+            _ => unreachable!("unsupported decimal byte width {}", BYTE_WIDTH),
+        };
+        let bound = "9".repeat(precision);
+        return Err(ArrowError::InvalidArgumentError(if big_int.to_string().starts_with('-') {
+            format!(
+                "{} is too small to store in a {} of precision {}. Min is -{}",
+                big_int, type_name, precision, bound
+            )
+        } else {
+            format!(
+                "{} is too large to store in a {} of precision {}. Max is {}",
+                big_int, type_name, precision, bound
+            )
+        }));
+    }
+    Ok(())
+}
+
+/// Formats `value`, a little-endian two's-complement integer, as a decimal
+/// string with the decimal point inserted `scale` digits from the right.
+fn decimal_bytes_to_string(value: &[u8], scale: usize) -> String {
+    let big_int = BigInt::from_signed_bytes_le(value);
+    let raw = big_int.to_string();
+    let (negative, digits) = match raw.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, raw.as_str()),
+    };
+
+    let mut result = String::new();
+    if negative {
+        result.push('-');
+    }
+    if scale == 0 {
+        result.push_str(digits);
+        return result;
+    }
+    if digits.len() <= scale {
+        result.push_str("0.");
+        result.push_str(&"0".repeat(scale - digits.len()));
+        result.push_str(digits);
+    } else {
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+        result.push_str(int_part);
+        result.push('.');
+        result.push_str(frac_part);
+    }
+    result
+}
+
+/// Parses a human-readable decimal string, e.g. `"-12.345"` or `".5"`, into
+/// its `i128` representation aligned to `scale` fractional digits.
+///
+/// An optional leading `+`/`-` is stripped, the integer and fractional
+/// (after the `.`, if any) digit groups are concatenated, and the result is
+/// padded with trailing zeros to exactly `scale` fractional digits before
+/// being parsed as an integer. Returns an error instead of panicking if the string
+/// contains non-digit characters, has more fractional digits than `scale`,
+/// or overflows `i128`.
+fn parse_decimal_str(s: &str, scale: usize) -> Result<i128> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let mut parts = s.splitn(2, '.');
+    let integer = parts.next().unwrap_or("");
+    let fraction = parts.next().unwrap_or("");
+
+    if fraction.len() > scale {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "parsing \"{}\" as decimal with scale {} would lose precision",
+            s, scale
+        )));
+    }
+
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "can't parse \"{}\" as a decimal number",
+            s
+        )));
+    }
+
+    let mut digits = String::with_capacity(integer.len() + scale);
+    digits.push_str(integer);
+    digits.push_str(fraction);
+    digits.push_str(&"0".repeat(scale - fraction.len()));
+
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "can't parse \"{}\" as a decimal number",
+            s
+        )));
+    }
+
+    let mut value: i128 = 0;
+    for b in digits.bytes() {
+        let digit = (b - b'0') as i128;
+        value = value.checked_mul(10).and_then(|v| v.checked_add(digit)).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "\"{}\" overflows the range of a 128-bit decimal value",
+                s
+            ))
+        })?;
+    }
+
+    Ok(if negative { -value } else { value })
+}
+
+/// Rescales `value`, currently expressed with `scale` fractional digits, to
+/// `new_scale` fractional digits: multiplying by a power of ten when
+/// `new_scale` is larger, or dividing (rounding half away from zero) when
+/// it's smaller. Returns an error instead of wrapping on overflow.
+fn rescale_decimal_value(value: i128, scale: usize, new_scale: usize) -> Result<i128> {
+    let overflow = || {
+        ArrowError::InvalidArgumentError(format!(
+            "{} would overflow casting from scale {} to scale {}",
+            value, scale, new_scale
+        ))
+    };
+
+    match new_scale.cmp(&scale) {
+        std::cmp::Ordering::Equal => Ok(value),
+        std::cmp::Ordering::Greater => {
+            let multiplier = 10_i128
+                .checked_pow((new_scale - scale) as u32)
+                .ok_or_else(overflow)?;
+            value.checked_mul(multiplier).ok_or_else(overflow)
+        }
+        std::cmp::Ordering::Less => {
+            let divisor = 10_i128.pow((scale - new_scale) as u32);
+            let half = divisor / 2;
+            let adjusted = if value.is_negative() {
+                value.checked_sub(half).ok_or_else(overflow)?
+            } else {
+                value.checked_add(half).ok_or_else(overflow)?
+            };
+            Ok(adjusted / divisor)
+        }
+    }
+}
+
+/// Represents a single 256-bit decimal value read from a [`Decimal256Array`].
+///
+/// Unlike [`Decimal128`], the value cannot be represented as an `i128`, so
+/// callers that need the raw magnitude should use [`Decimal256::raw_value`]
+/// or format the value with [`ToString`]/[`Display`](fmt::Display).
+#[derive(Debug)]
+pub struct Decimal256 {
+    value: [u8; 32],
+    precision: usize,
+    scale: usize,
+}
+
+impl Decimal256 {
+    fn new_from_bytes(precision: usize, scale: usize, value: [u8; 32]) -> Self {
+        Self {
+            value,
+            precision,
+            scale,
+        }
+    }
+
+    /// Returns the raw 32-byte little-endian two's-complement value.
+    pub fn raw_value(&self) -> &[u8; 32] {
+        &self.value
+    }
+
+    /// Returns the precision of the decimal this value belongs to.
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Returns the scale of the decimal this value belongs to.
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+}
+
+impl fmt::Display for Decimal256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", decimal_bytes_to_string(&self.value, self.scale))
+    }
+}
+
+/// A decimal array where each element is a fixed-width, `BYTE_WIDTH`-byte,
+/// little-endian two's-complement value with a shared precision and scale.
+///
+/// [`DecimalArray`] (16 bytes, `i128`-backed) and [`Decimal256Array`] (32
+/// bytes, backed by big-integer arithmetic since the values don't fit in an
+/// `i128`) are type aliases of this generic array. All the logic that
+/// doesn't depend on the native representation of a single value --
+/// offset/length bookkeeping, construction from [`ArrayData`] or a
+/// [`FixedSizeListArray`], string formatting, and precision validation -- is
+/// implemented once here against `BYTE_WIDTH`. The only per-width pieces are
+/// the value type returned by [`BasicDecimalArray::value`] (via the
+/// type-specific `impl` blocks below) and the max precision/scale.
+pub(crate) struct BasicDecimalArray<const BYTE_WIDTH: usize> {
+    data: ArrayData,
+    value_data: RawPtrBox<u8>,
+    precision: usize,
+    scale: usize,
+}
 
 /// `DecimalArray` stores fixed width decimal numbers,
 /// with a fixed precision and scale.
@@ -68,33 +291,81 @@ use crate::util::decimal::{BasicDecimal, Decimal128};
 ///    assert_eq!(6, decimal_array.scale());
 /// ```
 ///
-pub struct DecimalArray {
-    data: ArrayData,
-    value_data: RawPtrBox<u8>,
-    precision: usize,
-    scale: usize,
-}
+pub type DecimalArray = BasicDecimalArray<16>;
 
-impl DecimalArray {
-    const VALUE_LENGTH: i32 = 16;
+/// `Decimal256Array` stores fixed width 256-bit (32-byte) decimal numbers,
+/// with a fixed precision (up to 76 digits) and scale.
+///
+/// Since the values don't fit in an `i128`, range validation and
+/// string formatting are implemented with big-integer arithmetic instead.
+///
+/// # Examples
+///
+/// ```
+///    use arrow::array::{Array, Decimal256Array};
+///    use arrow::datatypes::DataType;
+///
+///    let mut bytes = [0_u8; 32];
+///    bytes[0..8].copy_from_slice(&8_887_000_000_i64.to_le_bytes());
+///    let decimal_array = Decimal256Array::from_iter_values(vec![bytes])
+///     .with_precision_and_scale(76, 6)
+///     .unwrap();
+///
+///    assert_eq!(&DataType::Decimal256(76, 6), decimal_array.data_type());
+///    assert_eq!("8887.000000", decimal_array.value_as_string(0));
+/// ```
+///
+pub type Decimal256Array = BasicDecimalArray<32>;
 
-    /// Returns the element at index `i`.
-    pub fn value(&self, i: usize) -> Decimal128 {
+impl<const BYTE_WIDTH: usize> BasicDecimalArray<BYTE_WIDTH> {
+    const VALUE_LENGTH: i32 = BYTE_WIDTH as i32;
+
+    /// Returns the `(max_precision, max_scale)` allowed for this byte width.
+    fn max_precision_and_scale() -> (usize, usize) {
+        match BYTE_WIDTH {
+            16 => (DECIMAL_MAX_PRECISION, DECIMAL_MAX_SCALE),
+            32 => (DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE),
+            _ => unreachable!("unsupported decimal byte width {}", BYTE_WIDTH),
+        }
+    }
+
+    /// Returns the [`DataType`] variant for this byte width with the given
+    /// precision and scale.
+    fn data_type_with(precision: usize, scale: usize) -> DataType {
+        match BYTE_WIDTH {
+            16 => DataType::Decimal(precision, scale),
+            32 => DataType::Decimal256(precision, scale),
+            _ => unreachable!("unsupported decimal byte width {}", BYTE_WIDTH),
+        }
+    }
+
+    /// Validates that the little-endian two's-complement integer in `raw`
+    /// fits within `precision` decimal digits, using the cheap `i128` table
+    /// lookup for 128-bit values and falling back to big-integer arithmetic
+    /// only for the wider 256-bit representation.
+    fn validate_value_precision(raw: &[u8], precision: usize) -> Result<()> {
+        match BYTE_WIDTH {
+            16 => {
+                let value = i128::from_le_bytes(raw.try_into().unwrap());
+                validate_decimal_precision(value, precision)
+            }
+            32 => validate_decimal_bytes_precision::<BYTE_WIDTH>(raw, precision),
+            _ => unreachable!("unsupported decimal byte width {}", BYTE_WIDTH),
+        }
+    }
+
+    /// Returns the raw little-endian two's-complement bytes for the element
+    /// at index `i`, without bounds checking beyond the array's own length.
+    fn raw_value(&self, i: usize) -> &[u8] {
         assert!(i < self.data.len(), "DecimalArray out of bounds access");
         let offset = i + self.data.offset();
-        let raw_val = unsafe {
+        unsafe {
             let pos = self.value_offset_at(offset);
             std::slice::from_raw_parts(
                 self.value_data.as_ptr().offset(pos as isize),
                 Self::VALUE_LENGTH as usize,
             )
-        };
-        let as_array = raw_val.try_into().unwrap();
-        Decimal128::new_from_i128(
-            self.precision,
-            self.scale,
-            i128::from_le_bytes(as_array),
-        )
+        }
     }
 
     /// Returns the offset for the element at index `i`.
@@ -125,7 +396,7 @@ impl DecimalArray {
 
     #[inline]
     pub fn value_as_string(&self, row: usize) -> String {
-        self.value(row).to_string()
+        decimal_bytes_to_string(self.raw_value(row), self.scale)
     }
 
     pub fn from_fixed_size_list_array(
@@ -145,10 +416,16 @@ impl DecimalArray {
             &DataType::UInt8,
             "DecimalArray can only be created from FixedSizeList<u8> arrays, mismatched data types."
         );
+        assert_eq!(
+            v.value_length(),
+            Self::VALUE_LENGTH,
+            "DecimalArray can only be created from FixedSizeList<u8> of width {}.",
+            BYTE_WIDTH
+        );
 
         let list_offset = v.offset();
         let child_offset = child_data.offset();
-        let builder = ArrayData::builder(DataType::Decimal(precision, scale))
+        let builder = ArrayData::builder(Self::data_type_with(precision, scale))
             .len(v.len())
             .add_buffer(child_data.buffers()[0].slice(child_offset))
             .null_bit_buffer(v.data_ref().null_buffer().cloned())
@@ -158,24 +435,6 @@ impl DecimalArray {
         Self::from(array_data)
     }
 
-    /// Creates a [DecimalArray] with default precision and scale,
-    /// based on an iterator of `i128` values without nulls
-    pub fn from_iter_values<I: IntoIterator<Item = i128>>(iter: I) -> Self {
-        let val_buf: Buffer = iter.into_iter().collect();
-        let data = unsafe {
-            ArrayData::new_unchecked(
-                Self::default_type(),
-                val_buf.len() / std::mem::size_of::<i128>(),
-                None,
-                None,
-                0,
-                vec![val_buf],
-                vec![],
-            )
-        };
-        DecimalArray::from(data)
-    }
-
     /// Return the precision (total digits) that can be stored by this array
     pub fn precision(&self) -> usize {
         self.precision
@@ -186,28 +445,29 @@ impl DecimalArray {
         self.scale
     }
 
-    /// Returns a DecimalArray with the same data as self, with the
+    /// Returns a `BasicDecimalArray` with the same data as self, with the
     /// specified precision.
     ///
     /// Returns an Error if:
-    /// 1. `precision` is larger than [`DECIMAL_MAX_PRECISION`]
-    /// 2. `scale` is larger than [`DECIMAL_MAX_SCALE`];
+    /// 1. `precision` is larger than the max precision for this byte width
+    /// 2. `scale` is larger than the max scale for this byte width
     /// 3. `scale` is > `precision`
     pub fn with_precision_and_scale(
         mut self,
         precision: usize,
         scale: usize,
     ) -> Result<Self> {
-        if precision > DECIMAL_MAX_PRECISION {
+        let (max_precision, max_scale) = Self::max_precision_and_scale();
+        if precision > max_precision {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "precision {} is greater than max {}",
-                precision, DECIMAL_MAX_PRECISION
+                precision, max_precision
             )));
         }
-        if scale > DECIMAL_MAX_SCALE {
+        if scale > max_scale {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "scale {} is greater than max {}",
-                scale, DECIMAL_MAX_SCALE
+                scale, max_scale
             )));
         }
         if scale > precision {
@@ -221,18 +481,20 @@ impl DecimalArray {
         // precision. For performance, only check if the precision is
         // decreased
         if precision < self.precision {
-            for v in self.iter().flatten() {
-                validate_decimal_precision(v, precision)?;
+            for i in 0..self.data.len() {
+                if !self.is_null(i) {
+                    Self::validate_value_precision(self.raw_value(i), precision)?;
+                }
             }
         }
 
         assert_eq!(
             self.data.data_type(),
-            &DataType::Decimal(self.precision, self.scale)
+            &Self::data_type_with(self.precision, self.scale)
         );
 
-        // safety: self.data is valid DataType::Decimal as checked above
-        let new_data_type = DataType::Decimal(precision, scale);
+        // safety: self.data is valid Decimal/Decimal256 as checked above
+        let new_data_type = Self::data_type_with(precision, scale);
         self.precision = precision;
         self.scale = scale;
         self.data = self.data.with_data_type(new_data_type);
@@ -242,21 +504,152 @@ impl DecimalArray {
     /// The default precision and scale used when not specified.
     pub fn default_type() -> DataType {
         // Keep maximum precision
-        DataType::Decimal(DECIMAL_MAX_PRECISION, DECIMAL_DEFAULT_SCALE)
+        let (max_precision, _) = Self::max_precision_and_scale();
+        Self::data_type_with(max_precision, DECIMAL_DEFAULT_SCALE)
+    }
+}
+
+impl DecimalArray {
+    /// Returns the element at index `i`.
+    pub fn value(&self, i: usize) -> Decimal128 {
+        let raw_val: [u8; 16] = self.raw_value(i).try_into().unwrap();
+        Decimal128::new_from_i128(self.precision, self.scale, i128::from_le_bytes(raw_val))
+    }
+
+    /// Creates a [DecimalArray] with default precision and scale,
+    /// based on an iterator of `i128` values without nulls
+    pub fn from_iter_values<I: IntoIterator<Item = i128>>(iter: I) -> Self {
+        let val_buf: Buffer = iter.into_iter().collect();
+        let data = unsafe {
+            ArrayData::new_unchecked(
+                Self::default_type(),
+                val_buf.len() / std::mem::size_of::<i128>(),
+                None,
+                None,
+                0,
+                vec![val_buf],
+                vec![],
+            )
+        };
+        DecimalArray::from(data)
+    }
+
+    /// Creates a [DecimalArray] from an iterator of human-readable decimal
+    /// strings, e.g. `"8887.000000"`, aligning each value to `scale`
+    /// fractional digits and validating it against `precision`.
+    ///
+    /// Returns an `ArrowError` rather than panicking if a string can't be
+    /// parsed, has more fractional digits than `scale` (which would lose
+    /// precision), or overflows `i128`.
+    pub fn from_str_values<'a, I: IntoIterator<Item = &'a str>>(
+        iter: I,
+        precision: usize,
+        scale: usize,
+    ) -> Result<Self> {
+        Self::from_opt_str_values(iter.into_iter().map(Some), precision, scale)
+    }
+
+    /// Like [`DecimalArray::from_str_values`], but accepts `None` for null
+    /// entries.
+    pub fn from_opt_str_values<'a, I: IntoIterator<Item = Option<&'a str>>>(
+        iter: I,
+        precision: usize,
+        scale: usize,
+    ) -> Result<Self> {
+        let values = iter
+            .into_iter()
+            .map(|s| match s {
+                Some(s) => {
+                    let value = parse_decimal_str(s, scale)?;
+                    validate_decimal_precision(value, precision)?;
+                    Ok(Some(value))
+                }
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<Option<i128>>>>()?;
+
+        let array: DecimalArray = values.into_iter().collect();
+        array.with_precision_and_scale(precision, scale)
+    }
+
+    /// Returns a new `DecimalArray` with the same values as `self`, rescaled
+    /// from `self.scale()` to `new_scale`.
+    ///
+    /// Unlike [`DecimalArray::with_precision_and_scale`], which only relabels
+    /// the `DataType` metadata, this transforms the underlying `i128`
+    /// values: multiplying by `10^(new_scale - scale)` when `new_scale` is
+    /// larger than the current scale, or dividing by `10^(scale - new_scale)`
+    /// (rounding half away from zero) when it's smaller. Each rescaled value
+    /// is validated against `new_precision`; an overflowing multiplication
+    /// returns an `ArrowError` instead of wrapping. Nulls are preserved.
+    pub fn cast_with_scale(self, new_precision: usize, new_scale: usize) -> Result<Self> {
+        let scale = self.scale;
+        let values = self
+            .iter()
+            .map(|v| match v {
+                None => Ok(None),
+                Some(v) => {
+                    let rescaled = rescale_decimal_value(v, scale, new_scale)?;
+                    validate_decimal_precision(rescaled, new_precision)?;
+                    Ok(Some(rescaled))
+                }
+            })
+            .collect::<Result<Vec<Option<i128>>>>()?;
+
+        let array: DecimalArray = values.into_iter().collect();
+        array.with_precision_and_scale(new_precision, new_scale)
+    }
+}
+
+impl Decimal256Array {
+    /// Returns the element at index `i`.
+    pub fn value(&self, i: usize) -> Decimal256 {
+        let raw_val: [u8; 32] = self.raw_value(i).try_into().unwrap();
+        Decimal256::new_from_bytes(self.precision, self.scale, raw_val)
+    }
+
+    /// Creates a [Decimal256Array] with default precision and scale,
+    /// based on an iterator of 32-byte little-endian two's-complement values
+    /// without nulls.
+    pub fn from_iter_values<I: IntoIterator<Item = [u8; 32]>>(iter: I) -> Self {
+        let val_buf: Buffer = iter.into_iter().flatten().collect();
+        let data = unsafe {
+            ArrayData::new_unchecked(
+                Self::default_type(),
+                val_buf.len() / Self::VALUE_LENGTH as usize,
+                None,
+                None,
+                0,
+                vec![val_buf],
+                vec![],
+            )
+        };
+        Decimal256Array::from(data)
     }
 }
 
-impl From<ArrayData> for DecimalArray {
+impl<const BYTE_WIDTH: usize> From<ArrayData> for BasicDecimalArray<BYTE_WIDTH> {
     fn from(data: ArrayData) -> Self {
         assert_eq!(
             data.buffers().len(),
             1,
             "DecimalArray data should contain 1 buffer only (values)"
         );
+        assert!(
+            data.buffers()[0].len() >= (data.offset() + data.len()) * BYTE_WIDTH,
+            "DecimalArray buffer is too small for offset {} and len {} ({} bytes each)",
+            data.offset(),
+            data.len(),
+            BYTE_WIDTH
+        );
         let values = data.buffers()[0].as_ptr();
         let (precision, scale) = match data.data_type() {
-            DataType::Decimal(precision, scale) => (*precision, *scale),
-            _ => panic!("Expected data type to be Decimal"),
+            DataType::Decimal(precision, scale) if BYTE_WIDTH == 16 => (*precision, *scale),
+            DataType::Decimal256(precision, scale) if BYTE_WIDTH == 32 => (*precision, *scale),
+            _ => panic!(
+                "Expected data type to be Decimal ({} byte width)",
+                BYTE_WIDTH
+            ),
         };
         Self {
             data,
@@ -267,8 +660,8 @@ impl From<ArrayData> for DecimalArray {
     }
 }
 
-impl From<DecimalArray> for ArrayData {
-    fn from(array: DecimalArray) -> Self {
+impl<const BYTE_WIDTH: usize> From<BasicDecimalArray<BYTE_WIDTH>> for ArrayData {
+    fn from(array: BasicDecimalArray<BYTE_WIDTH>) -> Self {
         array.data
     }
 }
@@ -325,9 +718,110 @@ impl<Ptr: Borrow<Option<i128>>> FromIterator<Ptr> for DecimalArray {
     }
 }
 
-impl fmt::Debug for DecimalArray {
+/// An iterator over the values of a [`Decimal256Array`].
+#[derive(Debug)]
+pub struct Decimal256Iter<'a> {
+    array: &'a Decimal256Array,
+    current: usize,
+    current_end: usize,
+}
+
+impl<'a> Decimal256Iter<'a> {
+    /// constructs a new iterator
+    pub fn new(array: &'a Decimal256Array) -> Self {
+        Self {
+            array,
+            current: 0,
+            current_end: array.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for Decimal256Iter<'a> {
+    type Item = Option<[u8; 32]>;
+
+    fn next(&mut self) -> Option<Option<[u8; 32]>> {
+        if self.current == self.current_end {
+            None
+        } else {
+            let index = self.current;
+            self.current += 1;
+            Some(if self.array.is_null(index) {
+                None
+            } else {
+                Some(*self.array.value(index).raw_value())
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.current_end - self.current;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> std::iter::ExactSizeIterator for Decimal256Iter<'a> {}
+
+impl<'a> IntoIterator for &'a Decimal256Array {
+    type Item = Option<[u8; 32]>;
+    type IntoIter = Decimal256Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Decimal256Iter::<'a>::new(self)
+    }
+}
+
+impl<'a> Decimal256Array {
+    /// constructs a new iterator
+    pub fn iter(&'a self) -> Decimal256Iter<'a> {
+        Decimal256Iter::new(self)
+    }
+}
+
+impl<Ptr: Borrow<Option<[u8; 32]>>> FromIterator<Ptr> for Decimal256Array {
+    fn from_iter<I: IntoIterator<Item = Ptr>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        let size_hint = upper.unwrap_or(lower);
+
+        let mut null_buf = BooleanBufferBuilder::new(size_hint);
+
+        let buffer: Buffer = iter
+            .flat_map(|item| {
+                if let Some(a) = item.borrow() {
+                    null_buf.append(true);
+                    *a
+                } else {
+                    null_buf.append(false);
+                    // arbitrary value for NULL
+                    [0; 32]
+                }
+            })
+            .collect();
+
+        let data = unsafe {
+            ArrayData::new_unchecked(
+                Self::default_type(),
+                null_buf.len(),
+                None,
+                Some(null_buf.into()),
+                0,
+                vec![buffer],
+                vec![],
+            )
+        };
+        Decimal256Array::from(data)
+    }
+}
+
+impl<const BYTE_WIDTH: usize> fmt::Debug for BasicDecimalArray<BYTE_WIDTH> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "DecimalArray<{}, {}>\n[\n", self.precision, self.scale)?;
+        let name = match BYTE_WIDTH {
+            16 => "DecimalArray",
+            32 => "Decimal256Array",
+            _ => "BasicDecimalArray",
+        };
+        write!(f, "{}<{}, {}>\n[\n", name, self.precision, self.scale)?;
         print_long_array(self, f, |array, index, f| {
             let formatted_decimal = array.value_as_string(index);
 
@@ -337,7 +831,7 @@ impl fmt::Debug for DecimalArray {
     }
 }
 
-impl Array for DecimalArray {
+impl<const BYTE_WIDTH: usize> Array for BasicDecimalArray<BYTE_WIDTH> {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -419,179 +913,379 @@ mod tests {
     }
 
     #[test]
-    fn test_decimal_from_iter_values() {
-        let array = DecimalArray::from_iter_values(vec![-100, 0, 101].into_iter());
-        assert_eq!(array.len(), 3);
-        assert_eq!(array.data_type(), &DataType::Decimal(38, 10));
-        assert_eq!(-100_i128, array.value(0).into());
-        assert!(!array.is_null(0));
-        assert_eq!(0_i128, array.value(1).into());
-        assert!(!array.is_null(1));
-        assert_eq!(101_i128, array.value(2).into());
-        assert!(!array.is_null(2));
-    }
+    fn test_decimal_array_from_fixed_size_list() {
+        let value_data = ArrayData::builder(DataType::UInt8)
+            .offset(16)
+            .len(48)
+            .add_buffer(Buffer::from_slice_ref(&[99999_i128, 12, 34, 56]))
+            .build()
+            .unwrap();
 
-    #[test]
-    fn test_decimal_from_iter() {
-        let array: DecimalArray = vec![Some(-100), None, Some(101)].into_iter().collect();
-        assert_eq!(array.len(), 3);
-        assert_eq!(array.data_type(), &DataType::Decimal(38, 10));
-        assert_eq!(-100_i128, array.value(0).into());
-        assert!(!array.is_null(0));
-        assert!(array.is_null(1));
-        assert_eq!(101_i128, array.value(2).into());
-        assert!(!array.is_null(2));
-    }
+        let null_buffer = Buffer::from_slice_ref(&[0b101]);
 
-    #[test]
-    fn test_decimal_iter() {
-        let data = vec![Some(-100), None, Some(101)];
-        let array: DecimalArray = data.clone().into_iter().collect();
+        // Construct a list array from the above two
+        let list_data_type = DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::UInt8, false)),
+            16,
+        );
+        let list_data = ArrayData::builder(list_data_type)
+            .len(2)
+            .null_bit_buffer(Some(null_buffer))
+            .offset(1)
+            .add_child_data(value_data)
+            .build()
+            .unwrap();
+        let list_array = FixedSizeListArray::from(list_data);
+        let decimal = DecimalArray::from_fixed_size_list_array(list_array, 38, 0);
 
-        let collected: Vec<_> = array.iter().collect();
-        assert_eq!(data, collected);
+        assert_eq!(decimal.len(), 2);
+        assert!(decimal.is_null(0));
+        assert_eq!(decimal.value_as_string(1), "56".to_string());
     }
 
     #[test]
-    fn test_decimal_into_iter() {
-        let data = vec![Some(-100), None, Some(101)];
-        let array: DecimalArray = data.clone().into_iter().collect();
-
-        let collected: Vec<_> = array.into_iter().collect();
-        assert_eq!(data, collected);
+    fn test_decimal_array_from_str_values() {
+        let array = DecimalArray::from_str_values(
+            vec!["8887.000000", "-8887.000000", "123", ".5", "-.5"],
+            23,
+            6,
+        )
+        .unwrap();
+
+        assert_eq!(array.len(), 5);
+        assert_eq!("8887.000000", array.value_as_string(0));
+        assert_eq!("-8887.000000", array.value_as_string(1));
+        assert_eq!("123.000000", array.value_as_string(2));
+        assert_eq!("0.500000", array.value_as_string(3));
+        assert_eq!("-0.500000", array.value_as_string(4));
     }
 
     #[test]
-    fn test_decimal_iter_sized() {
-        let data = vec![Some(-100), None, Some(101)];
-        let array: DecimalArray = data.into_iter().collect();
-        let mut iter = array.into_iter();
+    fn test_decimal_array_from_opt_str_values() {
+        let array = DecimalArray::from_opt_str_values(
+            vec![Some("12.345"), None, Some("-1")],
+            23,
+            3,
+        )
+        .unwrap();
 
-        // is exact sized
         assert_eq!(array.len(), 3);
-
-        // size_hint is reported correctly
-        assert_eq!(iter.size_hint(), (3, Some(3)));
-        iter.next().unwrap();
-        assert_eq!(iter.size_hint(), (2, Some(2)));
-        iter.next().unwrap();
-        iter.next().unwrap();
-        assert_eq!(iter.size_hint(), (0, Some(0)));
-        assert!(iter.next().is_none());
-        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!("12.345", array.value_as_string(0));
+        assert!(array.is_null(1));
+        assert_eq!("-1.000", array.value_as_string(2));
     }
 
     #[test]
-    fn test_decimal_array_value_as_string() {
-        let arr = [123450, -123450, 100, -100, 10, -10, 0]
-            .into_iter()
-            .map(Some)
-            .collect::<DecimalArray>()
-            .with_precision_and_scale(6, 3)
-            .unwrap();
+    fn test_decimal_array_from_str_values_errors() {
+        assert_eq!(
+            DecimalArray::from_str_values(vec!["12.3456"], 23, 3)
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument error: parsing \"12.3456\" as decimal with scale 3 would lose precision"
+        );
+
+        assert_eq!(
+            DecimalArray::from_str_values(vec!["12.3x"], 23, 3)
+                .unwrap_err()
+                .to_string(),
+            "Invalid argument error: can't parse \"12.3x\" as a decimal number"
+        );
 
-        assert_eq!("123.450", arr.value_as_string(0));
-        assert_eq!("-123.450", arr.value_as_string(1));
-        assert_eq!("0.100", arr.value_as_string(2));
-        assert_eq!("-0.100", arr.value_as_string(3));
-        assert_eq!("0.010", arr.value_as_string(4));
-        assert_eq!("-0.010", arr.value_as_string(5));
-        assert_eq!("0.000", arr.value_as_string(6));
+        for (empty, stripped) in [("", ""), ("-", ""), ("+", ""), (".", ".")] {
+            assert_eq!(
+                DecimalArray::from_str_values(vec![empty], 23, 3)
+                    .unwrap_err()
+                    .to_string(),
+                format!(
+                    "Invalid argument error: can't parse \"{}\" as a decimal number",
+                    stripped
+                )
+            );
+        }
+
+        let too_big = "9".repeat(40);
+        assert_eq!(
+            DecimalArray::from_str_values(vec![too_big.as_str()], 40, 0)
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "Invalid argument error: \"{}\" overflows the range of a 128-bit decimal value",
+                too_big
+            )
+        );
     }
 
     #[test]
-    fn test_decimal_array_with_precision_and_scale() {
-        let arr = DecimalArray::from_iter_values([12345, 456, 7890, -123223423432432])
+    fn test_decimal_array_cast_with_scale_up() {
+        let array = DecimalArray::from_iter_values([123, -123, 0])
             .with_precision_and_scale(20, 2)
             .unwrap();
+        let rescaled = array.cast_with_scale(20, 5).unwrap();
 
-        assert_eq!(arr.data_type(), &DataType::Decimal(20, 2));
-        assert_eq!(arr.precision(), 20);
-        assert_eq!(arr.scale(), 2);
-
-        let actual: Vec<_> = (0..arr.len()).map(|i| arr.value_as_string(i)).collect();
-        let expected = vec!["123.45", "4.56", "78.90", "-1232234234324.32"];
-
-        assert_eq!(actual, expected);
+        assert_eq!(rescaled.data_type(), &DataType::Decimal(20, 5));
+        assert_eq!("1.23000", rescaled.value_as_string(0));
+        assert_eq!("-1.23000", rescaled.value_as_string(1));
+        assert_eq!("0.00000", rescaled.value_as_string(2));
     }
 
     #[test]
-    #[should_panic(
-        expected = "-123223423432432 is too small to store in a Decimal of precision 5. Min is -99999"
-    )]
-    fn test_decimal_array_with_precision_and_scale_out_of_range() {
-        DecimalArray::from_iter_values([12345, 456, 7890, -123223423432432])
-            // precision is too small to hold value
-            .with_precision_and_scale(5, 2)
+    fn test_decimal_array_cast_with_scale_down_rounds_half_away_from_zero() {
+        let array = DecimalArray::from_iter_values([12345, -12345, 12350, -12350])
+            .with_precision_and_scale(20, 4)
             .unwrap();
+        let rescaled = array.cast_with_scale(20, 2).unwrap();
+
+        assert_eq!(rescaled.data_type(), &DataType::Decimal(20, 2));
+        assert_eq!("1.23", rescaled.value_as_string(0));
+        assert_eq!("-1.23", rescaled.value_as_string(1));
+        // exact tie rounds away from zero
+        assert_eq!("1.24", rescaled.value_as_string(2));
+        assert_eq!("-1.24", rescaled.value_as_string(3));
     }
 
     #[test]
-    #[should_panic(expected = "precision 40 is greater than max 38")]
-    fn test_decimal_array_with_precision_and_scale_invalid_precision() {
-        DecimalArray::from_iter_values([12345, 456])
-            .with_precision_and_scale(40, 2)
+    fn test_decimal_array_cast_with_scale_preserves_nulls() {
+        let array: DecimalArray = vec![Some(100), None, Some(-100)]
+            .into_iter()
+            .collect::<DecimalArray>()
+            .with_precision_and_scale(20, 2)
             .unwrap();
+        let rescaled = array.cast_with_scale(20, 4).unwrap();
+
+        assert_eq!(rescaled.len(), 3);
+        assert!(!rescaled.is_null(0));
+        assert!(rescaled.is_null(1));
+        assert!(!rescaled.is_null(2));
+        assert_eq!("1.0000", rescaled.value_as_string(0));
+        assert_eq!("-1.0000", rescaled.value_as_string(2));
     }
 
     #[test]
-    #[should_panic(expected = "scale 40 is greater than max 38")]
-    fn test_decimal_array_with_precision_and_scale_invalid_scale() {
-        DecimalArray::from_iter_values([12345, 456])
-            .with_precision_and_scale(20, 40)
+    #[should_panic(expected = "is too large to store in a Decimal of precision 3")]
+    fn test_decimal_array_cast_with_scale_precision_error() {
+        DecimalArray::from_iter_values([12345])
+            .with_precision_and_scale(20, 2)
+            .unwrap()
+            .cast_with_scale(3, 2)
             .unwrap();
     }
 
     #[test]
-    #[should_panic(expected = "scale 10 is greater than precision 4")]
-    fn test_decimal_array_with_precision_and_scale_invalid_precision_and_scale() {
-        DecimalArray::from_iter_values([12345, 456])
-            .with_precision_and_scale(4, 10)
+    fn test_decimal_array_cast_with_scale_overflow() {
+        let array = DecimalArray::from_iter_values([i128::MAX / 10])
+            .with_precision_and_scale(38, 2)
             .unwrap();
-    }
 
-    #[test]
-    fn test_decimal_array_fmt_debug() {
-        let arr = [Some(8887000000), Some(-8887000000), None]
-            .iter()
-            .collect::<DecimalArray>()
-            .with_precision_and_scale(23, 6)
-            .unwrap();
+        let err = array.cast_with_scale(38, 4).unwrap_err();
+        assert!(err.to_string().contains("would overflow casting from scale 2 to scale 4"));
+    }
 
-        assert_eq!(
-            "DecimalArray<23, 6>\n[\n  8887.000000,\n  -8887.000000,\n  null,\n]",
-            format!("{:?}", arr)
-        );
+    /// Returns the 32-byte little-endian two's-complement representation of `v`.
+    fn decimal256_bytes(v: i128) -> [u8; 32] {
+        let mut bytes = if v.is_negative() { [255_u8; 32] } else { [0_u8; 32] };
+        bytes[0..16].copy_from_slice(&v.to_le_bytes());
+        bytes
     }
 
-    #[test]
-    fn test_decimal_array_from_fixed_size_list() {
-        let value_data = ArrayData::builder(DataType::UInt8)
-            .offset(16)
-            .len(48)
-            .add_buffer(Buffer::from_slice_ref(&[99999_i128, 12, 34, 56]))
-            .build()
-            .unwrap();
+    /// Generates one test module per `(array type, byte width)` pair,
+    /// covering the scenarios common to every `BasicDecimalArray` instance
+    /// regardless of backing width. Behavior that only `DecimalArray` has
+    /// (`DecimalBuilder`, string parsing, and rescaling) is tested directly
+    /// against `DecimalArray` above.
+    macro_rules! basic_decimal_array_tests {
+        (
+            $test_mod:ident,
+            $arr_ty:ty,
+            $max_precision:expr,
+            $max_scale:expr,
+            $fmt_precision:expr,
+            $byte_width:expr,
+            $encode:expr,
+            $to_bytes:expr
+        ) => {
+            mod $test_mod {
+                use super::*;
+
+                fn make(values: impl IntoIterator<Item = i128>) -> $arr_ty {
+                    <$arr_ty>::from_iter_values(values.into_iter().map($encode))
+                }
 
-        let null_buffer = Buffer::from_slice_ref(&[0b101]);
+                fn make_opt(values: impl IntoIterator<Item = Option<i128>>) -> $arr_ty {
+                    values.into_iter().map(|v| v.map($encode)).collect()
+                }
 
-        // Construct a list array from the above two
-        let list_data_type = DataType::FixedSizeList(
-            Box::new(Field::new("item", DataType::UInt8, false)),
-            16,
-        );
-        let list_data = ArrayData::builder(list_data_type)
-            .len(2)
-            .null_bit_buffer(Some(null_buffer))
-            .offset(1)
-            .add_child_data(value_data)
-            .build()
-            .unwrap();
-        let list_array = FixedSizeListArray::from(list_data);
-        let decimal = DecimalArray::from_fixed_size_list_array(list_array, 38, 0);
+                #[test]
+                fn from_iter_values_uses_default_type() {
+                    let array = <$arr_ty>::from_iter_values([-100, 0, 101].into_iter().map($encode));
+                    assert_eq!(array.len(), 3);
+                    assert_eq!(array.data_type(), &<$arr_ty>::default_type());
+                    assert_eq!("-0.0000000100", array.value_as_string(0));
+                    assert_eq!("0.0000000000", array.value_as_string(1));
+                    assert_eq!("0.0000000101", array.value_as_string(2));
+                }
 
-        assert_eq!(decimal.len(), 2);
-        assert!(decimal.is_null(0));
-        assert_eq!(decimal.value_as_string(1), "56".to_string());
+                #[test]
+                fn from_iter_round_trips_values_and_nulls() {
+                    let array = make_opt([Some(-100), None, Some(101)]);
+                    assert_eq!(array.len(), 3);
+                    assert!(!array.is_null(0));
+                    assert!(array.is_null(1));
+                    assert!(!array.is_null(2));
+                    assert_eq!("-0.0000000100", array.value_as_string(0));
+                    assert_eq!("0.0000000101", array.value_as_string(2));
+                }
+
+                #[test]
+                fn iter_size_hint_tracks_remaining_elements() {
+                    let array = make_opt([Some(-100), None, Some(101)]);
+                    let mut iter = array.iter();
+
+                    assert_eq!(iter.size_hint(), (3, Some(3)));
+                    iter.next().unwrap();
+                    assert_eq!(iter.size_hint(), (2, Some(2)));
+                    iter.next().unwrap();
+                    iter.next().unwrap();
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+                    assert!(iter.next().is_none());
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+                }
+
+                #[test]
+                fn value_as_string() {
+                    let arr = make([123450, -123450, 100, -100, 0])
+                        .with_precision_and_scale($fmt_precision, 3)
+                        .unwrap();
+
+                    assert_eq!("123.450", arr.value_as_string(0));
+                    assert_eq!("-123.450", arr.value_as_string(1));
+                    assert_eq!("0.100", arr.value_as_string(2));
+                    assert_eq!("-0.100", arr.value_as_string(3));
+                    assert_eq!("0.000", arr.value_as_string(4));
+                }
+
+                #[test]
+                fn with_precision_and_scale() {
+                    let arr = make([12345, 456, 7890, -123223423432432])
+                        .with_precision_and_scale($fmt_precision, 2)
+                        .unwrap();
+
+                    assert_eq!(arr.precision(), $fmt_precision);
+                    assert_eq!(arr.scale(), 2);
+
+                    let actual: Vec<_> =
+                        (0..arr.len()).map(|i| arr.value_as_string(i)).collect();
+                    let expected = vec!["123.45", "4.56", "78.90", "-1232234234324.32"];
+
+                    assert_eq!(actual, expected);
+                }
+
+                #[test]
+                #[should_panic(expected = "-123223423432432 is too small to store in a Decimal")]
+                fn with_precision_and_scale_out_of_range() {
+                    make([12345, 456, 7890, -123223423432432])
+                        // precision is too small to hold value
+                        .with_precision_and_scale(5, 2)
+                        .unwrap();
+                }
+
+                #[test]
+                #[should_panic(expected = "is greater than max")]
+                fn with_precision_and_scale_invalid_precision() {
+                    make([12345, 456])
+                        .with_precision_and_scale($max_precision + 1, 2)
+                        .unwrap();
+                }
+
+                #[test]
+                #[should_panic(expected = "is greater than max")]
+                fn with_precision_and_scale_invalid_scale() {
+                    make([12345, 456])
+                        .with_precision_and_scale($max_precision, $max_scale + 1)
+                        .unwrap();
+                }
+
+                #[test]
+                #[should_panic(expected = "scale 10 is greater than precision 4")]
+                fn with_precision_and_scale_invalid_precision_and_scale() {
+                    make([12345, 456])
+                        .with_precision_and_scale(4, 10)
+                        .unwrap();
+                }
+
+                #[test]
+                fn fmt_debug() {
+                    let arr = make_opt([Some(8887000000), Some(-8887000000), None])
+                        .with_precision_and_scale($fmt_precision, 6)
+                        .unwrap();
+
+                    assert_eq!(
+                        format!(
+                            "{}<{}, 6>\n[\n  8887.000000,\n  -8887.000000,\n  null,\n]",
+                            stringify!($arr_ty),
+                            $fmt_precision
+                        ),
+                        format!("{:?}", arr)
+                    );
+                }
+
+                #[test]
+                fn from_fixed_size_list() {
+                    let width: usize = $byte_width;
+                    let mut buf = vec![0_u8; width * 4];
+                    for (i, v) in [99999_i128, 12, 34, 56].into_iter().enumerate() {
+                        buf[i * width..(i + 1) * width].copy_from_slice(&$to_bytes(v));
+                    }
+
+                    let value_data = ArrayData::builder(DataType::UInt8)
+                        .offset(width)
+                        .len(width * 3)
+                        .add_buffer(Buffer::from_slice_ref(&buf))
+                        .build()
+                        .unwrap();
+
+                    let null_buffer = Buffer::from_slice_ref(&[0b101]);
+
+                    let list_data_type = DataType::FixedSizeList(
+                        Box::new(Field::new("item", DataType::UInt8, false)),
+                        width as i32,
+                    );
+                    let list_data = ArrayData::builder(list_data_type)
+                        .len(2)
+                        .null_bit_buffer(Some(null_buffer))
+                        .offset(1)
+                        .add_child_data(value_data)
+                        .build()
+                        .unwrap();
+                    let list_array = FixedSizeListArray::from(list_data);
+                    let decimal =
+                        <$arr_ty>::from_fixed_size_list_array(list_array, $max_precision, 0);
+
+                    assert_eq!(decimal.len(), 2);
+                    assert!(decimal.is_null(0));
+                    assert_eq!(decimal.value_as_string(1), "56".to_string());
+                }
+            }
+        };
     }
+
+    basic_decimal_array_tests!(
+        decimal128,
+        DecimalArray,
+        DECIMAL_MAX_PRECISION,
+        DECIMAL_MAX_SCALE,
+        20,
+        16,
+        |v: i128| v,
+        |v: i128| v.to_le_bytes().to_vec()
+    );
+    basic_decimal_array_tests!(
+        decimal256,
+        Decimal256Array,
+        DECIMAL256_MAX_PRECISION,
+        DECIMAL256_MAX_SCALE,
+        40,
+        32,
+        decimal256_bytes,
+        |v: i128| decimal256_bytes(v).to_vec()
+    );
 }